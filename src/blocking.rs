@@ -0,0 +1,115 @@
+//! A blocking (synchronous) variant of the WebScrapingAPI client.
+//!
+//! This module is gated behind the `blocking` cargo feature and mirrors the
+//! async client's `get`/`post`/`put` and `raw_*` methods on top of
+//! [`reqwest::blocking`], so sync programs (CLI tools, scripts, test harnesses)
+//! can make a call without spinning up a Tokio runtime. It shares the
+//! [`QueryBuilder`](crate::QueryBuilder) and the parameter-encoding logic with
+//! the async surface so the two stay in lockstep. Each request is sent once:
+//! the async client's [`RetryPolicy`](crate::RetryPolicy) and middleware chain
+//! are not applied here.
+//!
+//! ```rust
+//! # fn get_example(wsa: &shifter::blocking::WebScrapingAPI) -> Result<(), Box<dyn std::error::Error>> {
+//! let mut query_builder = shifter::QueryBuilder::new();
+//!
+//! query_builder.url("http://httpbin.org/headers");
+//! query_builder.render_js(true);
+//!
+//! let html = wsa.get(query_builder)?.text()?;
+//!
+//! println!("{}", html);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::error::Error;
+use reqwest::blocking::{Client, Response};
+use reqwest::header::HeaderMap;
+
+use crate::{params_to_api_url, QueryBuilder, DEFAULT_BASE_URL};
+
+/// The blocking WebScrapingAPI client that makes the requests synchronously
+pub struct WebScrapingAPI<'a> {
+    key: &'a str,
+    client: Client,
+    base_url: String,
+}
+
+impl<'a> WebScrapingAPI<'a> {
+    /// The WebScrapingAPI constructor
+    pub fn new(api_key: &str) -> WebScrapingAPI {
+        WebScrapingAPI {
+            key: api_key,
+            client: Client::new(),
+            base_url: String::from(DEFAULT_BASE_URL),
+        }
+    }
+
+    /// Parses parameters and encodes them correctly
+    fn params_to_api_url<V: AsRef<str>>(&self, params: HashMap<&str, V>) -> String {
+        params_to_api_url(&self.base_url, self.key, params)
+    }
+
+    /// WebScrapingAPI get request based on the query builder
+    pub fn get(&self, query_builder: QueryBuilder<'a>) -> Result<Response, Box<dyn Error>> {
+        let headers: HeaderMap = (&query_builder.get_headers()).try_into().expect("Invalid headers");
+        let api_url = self.params_to_api_url(query_builder.get_params());
+        let mut request = self.client.get(api_url).headers(headers);
+        if let Some(timeout) = query_builder.get_request_timeout() {
+            request = request.timeout(timeout);
+        }
+        let response = request.send()?;
+        Ok(response)
+    }
+
+    /// WebScrapingAPI post request based on the query builder
+    pub fn post(&self, query_builder: QueryBuilder<'a>) -> Result<Response, Box<dyn Error>> {
+        let headers: HeaderMap = (&query_builder.get_headers()).try_into().expect("Invalid headers");
+        let api_url = self.params_to_api_url(query_builder.get_params());
+        let mut request = self.client.post(api_url).json(&query_builder.get_body()).headers(headers);
+        if let Some(timeout) = query_builder.get_request_timeout() {
+            request = request.timeout(timeout);
+        }
+        let response = request.send()?;
+        Ok(response)
+    }
+
+    /// WebScrapingAPI put request based on the query builder
+    pub fn put(&self, query_builder: QueryBuilder<'a>) -> Result<Response, Box<dyn Error>> {
+        let headers: HeaderMap = (&query_builder.get_headers()).try_into().expect("Invalid headers");
+        let api_url = self.params_to_api_url(query_builder.get_params());
+        let mut request = self.client.put(api_url).json(&query_builder.get_body()).headers(headers);
+        if let Some(timeout) = query_builder.get_request_timeout() {
+            request = request.timeout(timeout);
+        }
+        let response = request.send()?;
+        Ok(response)
+    }
+
+    /// WebScrapingAPI get request based on HashMap raw parameters
+    pub fn raw_get(&self, params: HashMap<&str, &str>, headers: HashMap<String, String>) -> Result<Response, Box<dyn Error>> {
+        let headers: HeaderMap = (&headers).try_into().expect("Invalid headers");
+        let api_url = self.params_to_api_url(params);
+        let response = self.client.get(api_url).headers(headers).send()?;
+        Ok(response)
+    }
+
+    /// WebScrapingAPI post request based on HashMap raw parameters
+    pub fn raw_post(&self, params: HashMap<&str, &str>, headers: HashMap<String, String>, body: HashMap<&str, &str>) -> Result<Response, Box<dyn Error>> {
+        let headers: HeaderMap = (&headers).try_into().expect("Invalid headers");
+        let api_url = self.params_to_api_url(params);
+        let response = self.client.post(api_url).json(&body).headers(headers).send()?;
+        Ok(response)
+    }
+
+    /// WebScrapingAPI put request based on HashMap raw parameters
+    pub fn raw_put(&self, params: HashMap<&str, &str>, headers: HashMap<String, String>, body: HashMap<&str, &str>) -> Result<Response, Box<dyn Error>> {
+        let headers: HeaderMap = (&headers).try_into().expect("Invalid headers");
+        let api_url = self.params_to_api_url(params);
+        let response = self.client.put(api_url).json(&body).headers(headers).send()?;
+        Ok(response)
+    }
+}
@@ -16,7 +16,7 @@
 //! let mut query_builder = QueryBuilder::new();
 //!
 //! query_builder.url("http://httpbin.org/headers");
-//! query_builder.render_js("1");
+//! query_builder.render_js(true);
 //!
 //! let mut headers: HashMap<String, String> = HashMap::new();
 //! headers.insert("Wsa-test".to_string(), "abcd".to_string());
@@ -56,34 +56,322 @@
 
 extern crate reqwest;
 
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
+
 use std::collections::HashMap;
 use urlencoding::encode;
 use reqwest::{Response, Client, header::HeaderMap};
 use std::error::Error;
 use std::convert::TryInto;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use rand::Rng;
+use serde::de::DeserializeOwned;
 
 /// The query builder struct that contains the params, headers and body of a request
 pub struct QueryBuilder<'a> {
-    params: HashMap<&'a str, &'a str>,
+    params: HashMap<&'a str, String>,
     headers: HashMap<String, String>,
     body: HashMap<&'a str, &'a str>,
+    request_timeout: Option<Duration>,
+}
+
+/// The proxy pool a request is routed through
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProxyType {
+    /// Fast, cheaper datacenter proxies
+    Datacenter,
+    /// Residential proxies that are harder to detect and block
+    Residential,
+}
+
+impl ProxyType {
+    /// The exact string the API expects for this proxy type
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            ProxyType::Datacenter => "datacenter",
+            ProxyType::Residential => "residential",
+        }
+    }
+}
+
+/// The device profile the request is emulated from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Device {
+    /// A desktop browser
+    Desktop,
+    /// A mobile browser
+    Mobile,
+    /// A tablet browser
+    Tablet,
+}
+
+impl Device {
+    /// The exact string the API expects for this device
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            Device::Desktop => "desktop",
+            Device::Mobile => "mobile",
+            Device::Tablet => "tablet",
+        }
+    }
+}
+
+/// The page lifecycle event to wait for before capturing the result
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WaitUntil {
+    /// Wait for the `load` event
+    Load,
+    /// Wait for the `DOMContentLoaded` event
+    DomContentLoaded,
+    /// Wait until there are no network connections for at least 500ms
+    NetworkIdle0,
+    /// Wait until there are no more than 2 network connections for at least 500ms
+    NetworkIdle2,
+}
+
+impl WaitUntil {
+    /// The exact string the API expects for this wait condition
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            WaitUntil::Load => "load",
+            WaitUntil::DomContentLoaded => "domcontentloaded",
+            WaitUntil::NetworkIdle0 => "networkidle0",
+            WaitUntil::NetworkIdle2 => "networkidle2",
+        }
+    }
+}
+
+/// An ISO 3166-1 alpha-2 country code for the exit proxy.
+///
+/// The commonly requested countries have dedicated variants; any other
+/// supported code can still be passed through the raw [`QueryBuilder::param`]
+/// path for forward-compatibility.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum CountryCode {
+    Us,
+    Gb,
+    De,
+    Fr,
+    Es,
+    It,
+    Ca,
+    Au,
+    Br,
+    In,
+    Jp,
+    Nl,
+}
+
+impl CountryCode {
+    /// The lowercase two-letter code the API expects
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            CountryCode::Us => "us",
+            CountryCode::Gb => "gb",
+            CountryCode::De => "de",
+            CountryCode::Fr => "fr",
+            CountryCode::Es => "es",
+            CountryCode::It => "it",
+            CountryCode::Ca => "ca",
+            CountryCode::Au => "au",
+            CountryCode::Br => "br",
+            CountryCode::In => "in",
+            CountryCode::Jp => "jp",
+            CountryCode::Nl => "nl",
+        }
+    }
+}
+
+/// The output a single [`Field`] extracts from the element its selector matches
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Output {
+    /// The text content of the element
+    Text,
+    /// The inner HTML of the element
+    Html,
+    /// The value of the named attribute
+    Attribute(String),
+}
+
+/// A single extraction field: a CSS selector plus what to pull out of it.
+///
+/// A field defaults to extracting [`Output::Text`]. Chain [`html`](Self::html),
+/// [`attribute`](Self::attribute), [`list`](Self::list) and
+/// [`children`](Self::children) to refine it.
+#[derive(Clone, Debug)]
+pub struct Field {
+    selector: String,
+    output: Output,
+    list: bool,
+    children: Option<ExtractRules>,
+}
+
+impl Field {
+    /// Starts a field bound to the given CSS selector, extracting its text by default
+    pub fn new(selector: &str) -> Field {
+        Field {
+            selector: selector.to_string(),
+            output: Output::Text,
+            list: false,
+            children: None,
+        }
+    }
+
+    /// Extracts the text content of the matched element
+    pub fn text(mut self) -> Field {
+        self.output = Output::Text;
+        self
+    }
+
+    /// Extracts the inner HTML of the matched element
+    pub fn html(mut self) -> Field {
+        self.output = Output::Html;
+        self
+    }
+
+    /// Extracts the value of the named attribute
+    pub fn attribute(mut self, name: &str) -> Field {
+        self.output = Output::Attribute(name.to_string());
+        self
+    }
+
+    /// Marks the field as a list, matching every element rather than the first
+    pub fn list(mut self) -> Field {
+        self.list = true;
+        self
+    }
+
+    /// Attaches nested rules extracted relative to each matched element
+    pub fn children(mut self, children: ExtractRules) -> Field {
+        self.children = Some(children);
+        self
+    }
+
+    /// Builds the JSON object describing this field
+    fn to_value(&self) -> serde_json::Value {
+        let mut object = serde_json::Map::new();
+        object.insert("selector".to_string(), serde_json::Value::String(self.selector.clone()));
+
+        match &self.output {
+            Output::Text => {
+                object.insert("output".to_string(), serde_json::Value::String("text".to_string()));
+            }
+            Output::Html => {
+                object.insert("output".to_string(), serde_json::Value::String("html".to_string()));
+            }
+            Output::Attribute(name) => {
+                object.insert("output".to_string(), serde_json::Value::String("attribute".to_string()));
+                object.insert("attr".to_string(), serde_json::Value::String(name.clone()));
+            }
+        }
+
+        if self.list {
+            object.insert("type".to_string(), serde_json::Value::String("list".to_string()));
+        }
+
+        if let Some(children) = &self.children {
+            object.insert("children".to_string(), children.to_value());
+        }
+
+        serde_json::Value::Object(object)
+    }
+}
+
+/// A fluent builder for the `extract_rules` schema.
+///
+/// Declare named fields bound to CSS selectors and serialize the whole schema
+/// to the JSON string the API consumes, instead of hand-writing escaped JSON:
+///
+/// ```ignore
+/// let rules = ExtractRules::new()
+///     .field("title", Field::new("h1"))
+///     .field("links", Field::new("a").list().attribute("href"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ExtractRules {
+    fields: Vec<(String, Field)>,
+}
+
+impl ExtractRules {
+    /// Starts an empty set of extraction rules
+    pub fn new() -> ExtractRules {
+        ExtractRules { fields: Vec::new() }
+    }
+
+    /// Adds a named field to the schema
+    pub fn field(mut self, name: &str, field: Field) -> ExtractRules {
+        self.fields.push((name.to_string(), field));
+        self
+    }
+
+    /// Builds the JSON object describing this set of rules
+    fn to_value(&self) -> serde_json::Value {
+        let mut object = serde_json::Map::new();
+        for (name, field) in &self.fields {
+            object.insert(name.clone(), field.to_value());
+        }
+        serde_json::Value::Object(object)
+    }
+
+    /// Serializes the rules to the JSON string the `extract_rules` parameter expects
+    pub fn to_json(&self) -> String {
+        self.to_value().to_string()
+    }
+}
+
+/// A value accepted by [`QueryBuilder::extract_rules`]: a raw JSON string or an [`ExtractRules`].
+pub trait IntoExtractRules {
+    /// Converts the value into the serialized `extract_rules` JSON string
+    fn into_extract_rules(self) -> String;
+}
+
+impl IntoExtractRules for &str {
+    fn into_extract_rules(self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoExtractRules for ExtractRules {
+    fn into_extract_rules(self) -> String {
+        self.to_json()
+    }
 }
 
 impl<'a> QueryBuilder<'a> {
     /// QueryBuilder constructor
     pub fn new() -> QueryBuilder<'a> {
-        QueryBuilder { 
-            params: HashMap::new(), 
+        QueryBuilder {
+            params: HashMap::new(),
             headers: HashMap::new(),
             body: HashMap::new(),
+            request_timeout: None,
         }
     }
 
+    /// Sets a request-level timeout that overrides the client default for this call
+    pub fn request_timeout(&mut self, value: Duration) {
+        self.request_timeout = Some(value);
+    }
+
+    /// Returns the request-level timeout, if one was set
+    pub fn get_request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
     /// Returns a clone of the params hashmap
-    pub fn get_params(&self) -> HashMap<&str, &str> {
+    pub fn get_params(&self) -> HashMap<&str, String> {
         self.params.clone()
     }
 
+    /// Sets an arbitrary parameter by name, for parameters without a typed setter yet
+    pub fn param(&mut self, key: &'a str, value: &str) {
+        self.params.insert(key, value.to_string());
+    }
+
     /// Sets the headers hashmap for the QueryBuilder
     pub fn headers(&mut self, headers: HashMap<String, String>) {
         self.headers = headers;
@@ -106,178 +394,478 @@ impl<'a> QueryBuilder<'a> {
 
     /// Sets the url parameter for the QueryBuilder
     pub fn url(&mut self, value: &'a str) {
-        self.params.insert("url", value);
+        self.params.insert("url", value.to_string());
     }
 
     /// Returns the url parameter for the QueryBuilder
     pub fn get_url(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("url").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the render_js parameter for the QueryBuilder
-    pub fn render_js(&mut self, value: &'a str) {
-        self.params.insert("render_js", value);
+    pub fn render_js(&mut self, value: bool) {
+        self.params.insert("render_js", if value { "1" } else { "0" }.to_string());
     }
 
     /// Returns the render_js parameter for the QueryBuilder
     pub fn get_render_js(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("render_js").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the proxy_type parameter for the QueryBuilder
-    pub fn proxy_type(&mut self, value: &'a str) {
-        self.params.insert("proxy_type", value);
+    pub fn proxy_type(&mut self, value: ProxyType) {
+        self.params.insert("proxy_type", value.as_param().to_string());
     }
 
     /// Returns the proxy_type parameter for the QueryBuilder
     pub fn get_proxy_type(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("proxy_type").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the country parameter for the QueryBuilder
-    pub fn country(&mut self, value: &'a str) {
-        self.params.insert("country", value);
+    pub fn country(&mut self, value: CountryCode) {
+        self.params.insert("country", value.as_param().to_string());
     }
 
     /// Returns the country parameter for the QueryBuilder
     pub fn get_country(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("country").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the keep_headers parameter for the QueryBuilder
     pub fn keep_headers(&mut self, value: &'a str) {
-        self.params.insert("keep_headers", value);
+        self.params.insert("keep_headers", value.to_string());
     }
 
     /// Returns the keep_headers parameter for the QueryBuilder
     pub fn get_keep_headers(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("keep_headers").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the session parameter for the QueryBuilder
     pub fn session(&mut self, value: &'a str) {
-        self.params.insert("session", value);
+        self.params.insert("session", value.to_string());
     }
 
     /// Returns the session parameter for the QueryBuilder
     pub fn get_session(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("session").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the timeout parameter for the QueryBuilder
-    pub fn timeout(&mut self, value: &'a str) {
-        self.params.insert("timeout", value);
+    pub fn timeout(&mut self, value: Duration) {
+        self.params.insert("timeout", value.as_millis().to_string());
     }
 
     /// Returns the timeout parameter for the QueryBuilder
     pub fn get_timeout(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("timeout").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the device parameter for the QueryBuilder
-    pub fn device(&mut self, value: &'a str) {
-        self.params.insert("device", value);
+    pub fn device(&mut self, value: Device) {
+        self.params.insert("device", value.as_param().to_string());
     }
 
     /// Returns the device parameter for the QueryBuilder
     pub fn get_device(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("device").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the wait_until parameter for the QueryBuilder
-    pub fn wait_until(&mut self, value: &'a str) {
-        self.params.insert("wait_until", value);
+    pub fn wait_until(&mut self, value: WaitUntil) {
+        self.params.insert("wait_until", value.as_param().to_string());
     }
 
     /// Returns the wait_until parameter for the QueryBuilder
     pub fn get_wait_until(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("wait_until").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the wait_for parameter for the QueryBuilder
     pub fn wait_for(&mut self, value: &'a str) {
-        self.params.insert("wait_for", value);
+        self.params.insert("wait_for", value.to_string());
     }
 
     /// Returns the wait_for parameter for the QueryBuilder
     pub fn get_wait_for(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("wait_for").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the wait_for_css parameter for the QueryBuilder
     pub fn wait_for_css(&mut self, value: &'a str) {
-        self.params.insert("wait_for_css", value);
+        self.params.insert("wait_for_css", value.to_string());
     }
 
     /// Returns the wait_for_css parameter for the QueryBuilder
     pub fn get_wait_for_css(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("wait_for_css").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the screenshot parameter for the QueryBuilder
     pub fn screenshot(&mut self, value: &'a str) {
-        self.params.insert("screenshot", value);
+        self.params.insert("screenshot", value.to_string());
     }
 
     /// Returns the screenshot parameter for the QueryBuilder
     pub fn get_screenshot(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("screenshot").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the extract_rules parameter for the QueryBuilder
-    pub fn extract_rules(&mut self, value: &'a str) {
-        self.params.insert("extract_rules", value);
+    ///
+    /// Accepts either a raw pre-serialized JSON `&str` or a typed [`ExtractRules`] value.
+    pub fn extract_rules<R: IntoExtractRules>(&mut self, value: R) {
+        self.params.insert("extract_rules", value.into_extract_rules());
     }
 
     /// Returns the extract_rules parameter for the QueryBuilder
     pub fn get_extract_rules(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("extract_rules").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the disable_stealth parameter for the QueryBuilder
     pub fn disable_stealth(&mut self, value: &'a str) {
-        self.params.insert("disable_stealth", value);
+        self.params.insert("disable_stealth", value.to_string());
     }
 
     /// Returns the disable_stealth parameter for the QueryBuilder
     pub fn get_disable_stealth(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("disable_stealth").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the auto_parser parameter for the QueryBuilder
     pub fn auto_parser(&mut self, value: &'a str) {
-        self.params.insert("auto_parser", value);
+        self.params.insert("auto_parser", value.to_string());
     }
 
     /// Returns the auto_parser parameter for the QueryBuilder
     pub fn get_auto_parser(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("auto_parser").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
     }
 
     /// Sets the js_instructions parameter for the QueryBuilder
     pub fn js_instructions(&mut self, value: &'a str) {
-        self.params.insert("js_instructions", value);
+        self.params.insert("js_instructions", value.to_string());
     }
 
     /// Returns the js_instructions parameter for the QueryBuilder
     pub fn get_js_instructions(&self) -> Result<&str, Box<dyn Error>> {
         let result = self.params.get("js_instructions").unwrap();
-        Ok(*result)
+        Ok(result.as_str())
+    }
+}
+
+/// Controls how transient failures are retried before the client gives up.
+///
+/// A send is retried when the proxy pool replies with a `429` or a `5xx`, or
+/// when the request fails to connect or times out. Between attempts the client
+/// waits for an exponential backoff with full jitter
+/// (`random_between(0, min(cap_delay, base_delay * 2^attempt))`), unless the
+/// response carries a `Retry-After` header, in which case that value is honored
+/// instead.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    cap_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Builds a retry policy from the given number of attempts and the backoff bounds
+    pub fn new(max_attempts: u32, base_delay: Duration, cap_delay: Duration) -> RetryPolicy {
+        RetryPolicy { max_attempts, base_delay, cap_delay }
+    }
+
+    /// Sets the maximum number of retries performed after the initial attempt
+    pub fn max_attempts(mut self, value: u32) -> RetryPolicy {
+        self.max_attempts = value;
+        self
+    }
+
+    /// Sets the base delay used as the first step of the exponential backoff
+    pub fn base_delay(mut self, value: Duration) -> RetryPolicy {
+        self.base_delay = value;
+        self
+    }
+
+    /// Sets the upper bound the computed backoff is clamped to
+    pub fn cap_delay(mut self, value: Duration) -> RetryPolicy {
+        self.cap_delay = value;
+        self
+    }
+
+    /// Computes the full-jitter backoff to wait before the given retry attempt
+    fn backoff(&self, attempt: u32) -> Duration {
+        let base = self.base_delay.as_millis();
+        let exp = base.saturating_mul(1u128 << attempt.min(31));
+        let capped = exp.min(self.cap_delay.as_millis());
+        let jitter = rand::thread_rng().gen_range(0..=capped as u64);
+        Duration::from_millis(jitter)
+    }
+
+    /// Returns the delay to wait after `response`, honoring a `Retry-After` header when present
+    fn delay_for(&self, response: &Response, attempt: u32) -> Duration {
+        if let Some(header) = response.headers().get(reqwest::header::RETRY_AFTER) {
+            if let Ok(value) = header.to_str() {
+                let value = value.trim();
+                if let Ok(secs) = value.parse::<u64>() {
+                    return Duration::from_secs(secs);
+                }
+                if let Ok(when) = httpdate::parse_http_date(value) {
+                    if let Ok(delta) = when.duration_since(SystemTime::now()) {
+                        return delta;
+                    }
+                }
+            }
+        }
+        self.backoff(attempt)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(500),
+            cap_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+/// A cross-cutting behavior that can inspect or modify a request and its response.
+///
+/// Middleware is modelled on the [surf](https://docs.rs/surf) design: each
+/// implementation receives the outgoing [`reqwest::Request`] and a [`Next`]
+/// continuation. It may mutate the request, call `next.run(request).await` to
+/// forward it down the chain, then inspect or replace the returned [`Response`].
+/// Skipping the `next.run` call short-circuits the chain — useful for caching.
+#[async_trait::async_trait]
+pub trait Middleware: Send + Sync + 'static {
+    /// Handles the request, optionally forwarding it through `next`
+    async fn handle(&self, request: reqwest::Request, next: Next<'_>) -> Result<Response, Box<dyn Error>>;
+}
+
+/// The continuation of a middleware chain.
+///
+/// Calling [`Next::run`] invokes the next middleware in the chain, or performs
+/// the actual send (wrapped in the client's [`RetryPolicy`]) once the chain is
+/// exhausted.
+pub struct Next<'a> {
+    client: &'a Client,
+    retry_policy: &'a RetryPolicy,
+    middlewares: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    /// Forwards `request` to the next middleware, or sends it when the chain is exhausted
+    pub async fn run(mut self, request: reqwest::Request) -> Result<Response, Box<dyn Error>> {
+        match self.middlewares.split_first() {
+            Some((current, rest)) => {
+                self.middlewares = rest;
+                current.handle(request, self).await
+            }
+            None => execute_with_retry(self.client, self.retry_policy, request).await,
+        }
+    }
+}
+
+/// Sends `request`, retrying 429/5xx responses and connection errors per `retry_policy`.
+async fn execute_with_retry(client: &Client, retry_policy: &RetryPolicy, request: reqwest::Request) -> Result<Response, Box<dyn Error>> {
+    let mut attempt: u32 = 0;
+
+    loop {
+        let this_attempt = match request.try_clone() {
+            Some(cloned) => cloned,
+            None => return Ok(client.execute(request).await?),
+        };
+
+        match client.execute(this_attempt).await {
+            Ok(response) => {
+                let status = response.status();
+                let retryable = status.as_u16() == 429 || status.is_server_error();
+                if retryable && attempt < retry_policy.max_attempts {
+                    let delay = retry_policy.delay_for(&response, attempt);
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+                return Ok(response);
+            }
+            Err(err) => {
+                let retryable = err.is_timeout() || err.is_connect() || err.is_request();
+                if retryable && attempt < retry_policy.max_attempts {
+                    let delay = retry_policy.backoff(attempt);
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+                return Err(Box::new(err));
+            }
+        }
+    }
+}
+
+/// A typed wrapper around the raw [`reqwest::Response`] returned by the API.
+///
+/// A plain scrape returns the page body, reachable through [`text`](Self::text)
+/// or [`bytes`](Self::bytes). When `auto_parser` or `extract_rules` is set the
+/// API answers with structured JSON instead, which [`json`](Self::json) and
+/// [`extracted`](Self::extracted) deserialize into any `serde` type. When
+/// `screenshot` is requested [`screenshot_bytes`](Self::screenshot_bytes)
+/// yields the raw image bytes.
+pub struct ScrapingResponse {
+    inner: Response,
+}
+
+impl ScrapingResponse {
+    /// Returns the HTTP status code of the response
+    pub fn status(&self) -> reqwest::StatusCode {
+        self.inner.status()
+    }
+
+    /// Returns the response headers
+    pub fn headers(&self) -> &HeaderMap {
+        self.inner.headers()
+    }
+
+    /// Consumes the response and returns its body as text
+    pub async fn text(self) -> Result<String, Box<dyn Error>> {
+        Ok(self.inner.text().await?)
+    }
+
+    /// Consumes the response and returns its body as raw bytes
+    pub async fn bytes(self) -> Result<bytes::Bytes, Box<dyn Error>> {
+        Ok(self.inner.bytes().await?)
+    }
+
+    /// Consumes the response and deserializes the JSON body into `T`
+    pub async fn json<T: DeserializeOwned>(self) -> Result<T, Box<dyn Error>> {
+        Ok(self.inner.json::<T>().await?)
+    }
+
+    /// Consumes the response and deserializes the `extract_rules`/`auto_parser` payload into `T`
+    pub async fn extracted<T: DeserializeOwned>(self) -> Result<T, Box<dyn Error>> {
+        self.json().await
+    }
+
+    /// Consumes the response and returns the screenshot bytes, base64-decoding the body when needed
+    pub async fn screenshot_bytes(self) -> Result<Vec<u8>, Box<dyn Error>> {
+        let body = self.inner.bytes().await?;
+        match base64::decode(&body) {
+            Ok(decoded) => Ok(decoded),
+            Err(_) => Ok(body.to_vec()),
+        }
+    }
+}
+
+/// The default API endpoint every client targets unless a `base_url` overrides it
+const DEFAULT_BASE_URL: &str = "https://scrape.shifter.io/v1";
+
+/// Parses parameters and encodes them into the API url, shared by the async and blocking clients
+pub(crate) fn params_to_api_url<V: AsRef<str>>(base_url: &str, key: &str, params: HashMap<&str, V>) -> String {
+    let mut query_string: String = String::from("");
+
+    for (key, value) in params.into_iter() {
+        let value = value.as_ref();
+        let mut final_value = String::from(value);
+
+        if key == "url" {
+            final_value = encode(value).into_owned();
+        }
+
+        let parameter_query_string = format!("&{}={}", key, final_value);
+        query_string.push_str(&parameter_query_string);
+    }
+
+    format!("{}?api_key={}{}", base_url, key, query_string)
+}
+
+/// A builder for [`WebScrapingAPI`] that configures the underlying [`reqwest::Client`].
+///
+/// It mirrors reqwest's own `ClientBuilder`: set a global request `timeout`, a
+/// custom `user_agent`, an upstream `proxy`, or point the SDK at a staging host
+/// through `base_url`, then call [`build`](Self::build) to obtain a client.
+pub struct WebScrapingAPIBuilder<'a> {
+    key: &'a str,
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<reqwest::Proxy>,
+    base_url: String,
+}
+
+impl<'a> WebScrapingAPIBuilder<'a> {
+    /// Starts a builder for the given API key
+    pub fn new(api_key: &'a str) -> WebScrapingAPIBuilder<'a> {
+        WebScrapingAPIBuilder {
+            key: api_key,
+            timeout: None,
+            user_agent: None,
+            proxy: None,
+            base_url: String::from(DEFAULT_BASE_URL),
+        }
+    }
+
+    /// Sets the global request timeout applied to the underlying client
+    pub fn timeout(mut self, timeout: Duration) -> WebScrapingAPIBuilder<'a> {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request
+    pub fn user_agent(mut self, user_agent: &str) -> WebScrapingAPIBuilder<'a> {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Routes requests through the given upstream proxy
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> WebScrapingAPIBuilder<'a> {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Overrides the API endpoint the SDK targets (e.g. a staging host)
+    pub fn base_url(mut self, base_url: &str) -> WebScrapingAPIBuilder<'a> {
+        self.base_url = base_url.to_string();
+        self
+    }
+
+    /// Builds the configured [`WebScrapingAPI`] client
+    pub fn build(self) -> Result<WebScrapingAPI<'a>, Box<dyn Error>> {
+        let mut client = Client::builder();
+
+        if let Some(timeout) = self.timeout {
+            client = client.timeout(timeout);
+        }
+        if let Some(user_agent) = self.user_agent {
+            client = client.user_agent(user_agent);
+        }
+        if let Some(proxy) = self.proxy {
+            client = client.proxy(proxy);
+        }
+
+        Ok(WebScrapingAPI {
+            key: self.key,
+            client: client.build()?,
+            retry_policy: RetryPolicy::default(),
+            middlewares: Vec::new(),
+            base_url: self.base_url,
+        })
     }
 }
 
@@ -285,6 +873,9 @@ impl<'a> QueryBuilder<'a> {
 pub struct WebScrapingAPI<'a> {
     key: &'a str,
     client: Client,
+    retry_policy: RetryPolicy,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    base_url: String,
 }
 
 impl<'a> WebScrapingAPI<'a> {
@@ -293,72 +884,102 @@ impl<'a> WebScrapingAPI<'a> {
         WebScrapingAPI {
             key: api_key,
             client: Client::new(),
+            retry_policy: RetryPolicy::default(),
+            middlewares: Vec::new(),
+            base_url: String::from(DEFAULT_BASE_URL),
         }
     }
 
-    /// Parses parameters and encodes them correctly
-    fn params_to_api_url(&self, params: HashMap<&str, &str>) -> String {
-        let mut query_string: String = String::from("");
+    /// Starts a [`WebScrapingAPIBuilder`] for client-level configuration
+    pub fn builder(api_key: &'a str) -> WebScrapingAPIBuilder<'a> {
+        WebScrapingAPIBuilder::new(api_key)
+    }
 
-        for (key, value) in params.into_iter() {
-            let mut final_value = String::from(value);
+    /// Overrides the retry policy used to wrap every send
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> WebScrapingAPI<'a> {
+        self.retry_policy = retry_policy;
+        self
+    }
 
-            if key == "url" {
-                final_value = encode(value).into_owned();
-            }
+    /// Registers a middleware, appended after any previously registered ones
+    pub fn with_middleware(mut self, middleware: Arc<dyn Middleware>) -> WebScrapingAPI<'a> {
+        self.middlewares.push(middleware);
+        self
+    }
 
-            let parameter_query_string = format!("&{}={}", key, final_value);
-            query_string.push_str(&parameter_query_string);
-        }
+    /// Builds the request then drives it through the middleware chain down to the send
+    async fn run(&self, request: reqwest::RequestBuilder) -> Result<Response, Box<dyn Error>> {
+        let request = request.build()?;
+        let next = Next {
+            client: &self.client,
+            retry_policy: &self.retry_policy,
+            middlewares: &self.middlewares,
+        };
+        next.run(request).await
+    }
 
-        format!("https://scrape.shifter.io/v1?api_key={}{}", self.key, query_string)
+    /// Parses parameters and encodes them correctly
+    fn params_to_api_url<V: AsRef<str>>(&self, params: HashMap<&str, V>) -> String {
+        params_to_api_url(&self.base_url, self.key, params)
     }
 
     /// WebScrapingAPI get request based on the query builder
-    pub async fn get(&self, query_builder: QueryBuilder<'a>) -> Result<Response, Box<dyn Error>> {
+    pub async fn get(&self, query_builder: QueryBuilder<'a>) -> Result<ScrapingResponse, Box<dyn Error>> {
         let headers: HeaderMap = (&query_builder.get_headers()).try_into().expect("Invalid headers");
         let api_url = self.params_to_api_url(query_builder.get_params());
-        let response = self.client.get(api_url).headers(headers).send().await?;
-        Ok(response)
+        let mut request = self.client.get(api_url).headers(headers);
+        if let Some(timeout) = query_builder.get_request_timeout() {
+            request = request.timeout(timeout);
+        }
+        let inner = self.run(request).await?;
+        Ok(ScrapingResponse { inner })
     }
 
     /// WebScrapingAPI post request based on the query builder
-    pub async fn post(&self, query_builder: QueryBuilder<'a>) -> Result<Response, Box<dyn Error>> {
+    pub async fn post(&self, query_builder: QueryBuilder<'a>) -> Result<ScrapingResponse, Box<dyn Error>> {
         let headers: HeaderMap = (&query_builder.get_headers()).try_into().expect("Invalid headers");
         let api_url = self.params_to_api_url(query_builder.get_params());
-        let response = self.client.post(api_url).json(&query_builder.get_body()).headers(headers).send().await?;
-        Ok(response)
+        let mut request = self.client.post(api_url).json(&query_builder.get_body()).headers(headers);
+        if let Some(timeout) = query_builder.get_request_timeout() {
+            request = request.timeout(timeout);
+        }
+        let inner = self.run(request).await?;
+        Ok(ScrapingResponse { inner })
     }
 
     /// WebScrapingAPI put request based on the query builder
-    pub async fn put(&self, query_builder: QueryBuilder<'a>) -> Result<Response, Box<dyn Error>> {
+    pub async fn put(&self, query_builder: QueryBuilder<'a>) -> Result<ScrapingResponse, Box<dyn Error>> {
         let headers: HeaderMap = (&query_builder.get_headers()).try_into().expect("Invalid headers");
         let api_url = self.params_to_api_url(query_builder.get_params());
-        let response = self.client.put(api_url).json(&query_builder.get_body()).headers(headers).send().await?;
-        Ok(response)
+        let mut request = self.client.put(api_url).json(&query_builder.get_body()).headers(headers);
+        if let Some(timeout) = query_builder.get_request_timeout() {
+            request = request.timeout(timeout);
+        }
+        let inner = self.run(request).await?;
+        Ok(ScrapingResponse { inner })
     }
-    
+
     /// WebScrapingAPI get request based on HashMap raw parameters
-    pub async fn raw_get(&self, params: HashMap<&str, &str>, headers: HashMap<String, String>) -> Result<Response, Box<dyn Error>> {
+    pub async fn raw_get(&self, params: HashMap<&str, &str>, headers: HashMap<String, String>) -> Result<ScrapingResponse, Box<dyn Error>> {
         let headers: HeaderMap = (&headers).try_into().expect("Invalid headers");
         let api_url = self.params_to_api_url(params);
-        let response = self.client.get(api_url).headers(headers).send().await?;
-        Ok(response)
+        let inner = self.run(self.client.get(api_url).headers(headers)).await?;
+        Ok(ScrapingResponse { inner })
     }
 
     /// WebScrapingAPI post request based on HashMap raw parameters
-    pub async fn raw_post(&self, params: HashMap<&str, &str>, headers: HashMap<String, String>, body: HashMap<&str, &str>) -> Result<Response, Box<dyn Error>> {
+    pub async fn raw_post(&self, params: HashMap<&str, &str>, headers: HashMap<String, String>, body: HashMap<&str, &str>) -> Result<ScrapingResponse, Box<dyn Error>> {
         let headers: HeaderMap = (&headers).try_into().expect("Invalid headers");
         let api_url = self.params_to_api_url(params);
-        let response = self.client.post(api_url).json(&body).headers(headers).send().await?;
-        Ok(response)
+        let inner = self.run(self.client.post(api_url).json(&body).headers(headers)).await?;
+        Ok(ScrapingResponse { inner })
     }
 
     /// WebScrapingAPI put request based on HashMap raw parameters
-    pub async fn raw_put(&self, params: HashMap<&str, &str>, headers: HashMap<String, String>, body: HashMap<&str, &str>) -> Result<Response, Box<dyn Error>> {
+    pub async fn raw_put(&self, params: HashMap<&str, &str>, headers: HashMap<String, String>, body: HashMap<&str, &str>) -> Result<ScrapingResponse, Box<dyn Error>> {
         let headers: HeaderMap = (&headers).try_into().expect("Invalid headers");
         let api_url = self.params_to_api_url(params);
-        let response = self.client.put(api_url).json(&body).headers(headers).send().await?;
-        Ok(response)
+        let inner = self.run(self.client.put(api_url).json(&body).headers(headers)).await?;
+        Ok(ScrapingResponse { inner })
     }
 }
\ No newline at end of file